@@ -0,0 +1,19 @@
+use libcryptsetup_rs_sys as cryptsetup_sys;
+
+use crate::wipe::CryptWipe;
+
+/// Handle to an open `libcryptsetup` device context
+pub struct CryptDevice {
+    reference: *mut cryptsetup_sys::crypt_device,
+}
+
+impl CryptDevice {
+    pub(crate) fn as_ptr(&self) -> *mut cryptsetup_sys::crypt_device {
+        self.reference
+    }
+
+    /// Get a handle to perform wipe operations on this device
+    pub fn wipe_handle(&mut self) -> CryptWipe {
+        CryptWipe::new(self)
+    }
+}
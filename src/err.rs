@@ -0,0 +1,20 @@
+use std::{ffi::NulError, str::Utf8Error};
+
+use uuid::Error as UuidError;
+
+/// Error type returned by fallible operations throughout this crate
+#[derive(Debug)]
+pub enum LibcryptErr {
+    /// Underlying `libcryptsetup` call returned a negative errno
+    IOError(std::io::Error),
+    /// A raw value could not be converted into its typed Rust representation
+    InvalidConversion,
+    /// A Rust string contained an interior NUL byte and could not become a `CString`
+    NullByteError(NulError),
+    /// A string returned by `libcryptsetup` was not valid UTF-8
+    Utf8Error(Utf8Error),
+    /// A string returned by `libcryptsetup` failed to parse as a `Uuid`
+    UuidError(UuidError),
+    /// `libcryptsetup` unexpectedly returned a null pointer
+    NullPtr,
+}
@@ -0,0 +1,162 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use libcryptsetup_rs_sys as cryptsetup_sys;
+
+use crate::err::LibcryptErr;
+
+/// On-disk format of a crypt device, as reported by `crypt_get_type`
+pub enum EncryptionFormat {
+    Luks1,
+    Luks2,
+    Plain,
+    Verity,
+    Integrity,
+    TCrypt,
+    BitLk,
+    LoopAes,
+}
+
+impl FromStr for EncryptionFormat {
+    type Err = LibcryptErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "LUKS1" => EncryptionFormat::Luks1,
+            "LUKS2" => EncryptionFormat::Luks2,
+            "PLAIN" => EncryptionFormat::Plain,
+            "VERITY" => EncryptionFormat::Verity,
+            "INTEGRITY" => EncryptionFormat::Integrity,
+            "TCRYPT" => EncryptionFormat::TCrypt,
+            "BITLK" => EncryptionFormat::BitLk,
+            "LOOPAES" => EncryptionFormat::LoopAes,
+            _ => return Err(LibcryptErr::InvalidConversion),
+        })
+    }
+}
+
+/// Verity device parameters, as reported by `crypt_get_verity_info`
+pub struct CryptParamsVerity {
+    /// Hash algorithm used for the hash tree
+    pub hash_name: String,
+    /// Path to the data device
+    pub data_device: String,
+    /// Path to the hash device
+    pub hash_device: String,
+    /// Path to the FEC device, if any
+    pub fec_device: Option<String>,
+    /// Salt used when hashing
+    pub salt: Vec<u8>,
+    /// Superblock version
+    pub hash_type: u32,
+    /// Data block size in bytes
+    pub data_block_size: u32,
+    /// Hash block size in bytes
+    pub hash_block_size: u32,
+    /// Size of the data device in `data_block_size` blocks
+    pub data_size: u64,
+    /// Hash area offset in bytes
+    pub hash_area_offset: u64,
+    /// FEC area offset in bytes
+    pub fec_area_offset: u64,
+    /// Number of FEC roots
+    pub fec_roots: u32,
+    /// Raw flags, see [`crate::status::CryptVerityFlags`]
+    pub flags: u32,
+}
+
+impl TryFrom<&cryptsetup_sys::crypt_params_verity> for CryptParamsVerity {
+    type Error = LibcryptErr;
+
+    fn try_from(v: &cryptsetup_sys::crypt_params_verity) -> Result<Self, Self::Error> {
+        let salt = if v.salt.is_null() || v.salt_size == 0 {
+            Vec::new()
+        } else {
+            unsafe {
+                std::slice::from_raw_parts(v.salt as *const u8, v.salt_size as usize).to_vec()
+            }
+        };
+        Ok(CryptParamsVerity {
+            hash_name: from_str_ptr_to_owned!(v.hash_name)?,
+            data_device: from_str_ptr_to_owned!(v.data_device)?,
+            hash_device: from_str_ptr_to_owned!(v.hash_device)?,
+            fec_device: if v.fec_device.is_null() {
+                None
+            } else {
+                Some(from_str_ptr_to_owned!(v.fec_device)?)
+            },
+            salt,
+            hash_type: v.hash_type,
+            data_block_size: v.data_block_size,
+            hash_block_size: v.hash_block_size,
+            data_size: v.data_size,
+            hash_area_offset: v.hash_area_offset,
+            fec_area_offset: v.fec_area_offset,
+            fec_roots: v.fec_roots,
+            flags: v.flags,
+        })
+    }
+}
+
+/// Integrity device parameters, as reported by `crypt_get_integrity_info`
+pub struct CryptParamsIntegrity {
+    /// Size of the journal in bytes
+    pub journal_size: u64,
+    /// Number of free journal blocks that trigger a commit
+    pub journal_watermark: u32,
+    /// Maximum time in milliseconds before a journal commit
+    pub journal_commit_time: u32,
+    /// Number of interleaved sectors
+    pub interleave_sectors: u32,
+    /// Size of the integrity tag in bytes
+    pub tag_size: u32,
+    /// Sector size in bytes
+    pub sector_size: u32,
+    /// Number of sectors to buffer
+    pub buffer_sectors: u32,
+    /// Integrity algorithm name
+    pub integrity: Option<String>,
+    /// Size of the integrity key in bytes
+    pub integrity_key_size: u32,
+    /// Journal integrity algorithm name
+    pub journal_integrity: Option<String>,
+    /// Size of the journal integrity key in bytes
+    pub journal_integrity_key_size: u32,
+    /// Journal encryption algorithm name
+    pub journal_crypt: Option<String>,
+    /// Size of the journal encryption key in bytes
+    pub journal_crypt_key_size: u32,
+}
+
+impl TryFrom<&cryptsetup_sys::crypt_params_integrity> for CryptParamsIntegrity {
+    type Error = LibcryptErr;
+
+    fn try_from(v: &cryptsetup_sys::crypt_params_integrity) -> Result<Self, Self::Error> {
+        Ok(CryptParamsIntegrity {
+            journal_size: v.journal_size,
+            journal_watermark: v.journal_watermark,
+            journal_commit_time: v.journal_commit_time,
+            interleave_sectors: v.interleave_sectors,
+            tag_size: v.tag_size,
+            sector_size: v.sector_size,
+            buffer_sectors: v.buffer_sectors,
+            integrity: if v.integrity.is_null() {
+                None
+            } else {
+                Some(from_str_ptr_to_owned!(v.integrity)?)
+            },
+            integrity_key_size: v.integrity_key_size,
+            journal_integrity: if v.journal_integrity.is_null() {
+                None
+            } else {
+                Some(from_str_ptr_to_owned!(v.journal_integrity)?)
+            },
+            journal_integrity_key_size: v.journal_integrity_key_size,
+            journal_crypt: if v.journal_crypt.is_null() {
+                None
+            } else {
+                Some(from_str_ptr_to_owned!(v.journal_crypt)?)
+            },
+            journal_crypt_key_size: v.journal_crypt_key_size,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+#[macro_use]
+mod macros;
+
+mod device;
+mod err;
+mod format;
+mod status;
+mod wipe;
+
+pub use crate::{
+    device::CryptDevice,
+    err::LibcryptErr,
+    format::{CryptParamsIntegrity, CryptParamsVerity, EncryptionFormat},
+    status::*,
+    wipe::*,
+};
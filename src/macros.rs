@@ -0,0 +1,43 @@
+macro_rules! to_cstring {
+    ($str:expr) => {
+        std::ffi::CString::new($str).map_err(crate::err::LibcryptErr::NullByteError)
+    };
+}
+
+macro_rules! errno {
+    ($rc:expr) => {{
+        let rc = $rc;
+        if rc < 0 {
+            Err(crate::err::LibcryptErr::IOError(
+                std::io::Error::from_raw_os_error(-rc),
+            ))
+        } else {
+            Ok(rc)
+        }
+    }};
+}
+
+macro_rules! from_str_ptr {
+    ($ptr:expr) => {{
+        let ptr = unsafe { $ptr };
+        if ptr.is_null() {
+            Err(crate::err::LibcryptErr::NullPtr)
+        } else {
+            unsafe { std::ffi::CStr::from_ptr(ptr) }
+                .to_str()
+                .map_err(crate::err::LibcryptErr::Utf8Error)
+        }
+    }};
+}
+
+macro_rules! from_str_ptr_to_owned {
+    ($ptr:expr) => {
+        from_str_ptr!($ptr).map(|s| s.to_string())
+    };
+}
+
+macro_rules! try_int_to_return {
+    ($rc:expr, $enum:ty) => {
+        errno!($rc).and_then(|rc| <$enum>::try_from(rc as u32))
+    };
+}
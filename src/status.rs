@@ -3,13 +3,87 @@ use std::{convert::TryFrom, os::raw::c_int, path::Path, str::FromStr};
 use crate::{
     device::CryptDevice,
     err::LibcryptErr,
-    format::{CryptParamsIntegrity, CryptParamsVerity},
+    format::{CryptParamsIntegrity, CryptParamsVerity, EncryptionFormat},
 };
 
+use bitflags::bitflags;
 use libcryptsetup_rs_sys as cryptsetup_sys;
 
 use uuid::Uuid;
 
+bitflags! {
+    /// Flags for `crypt_dump_json`
+    pub struct CryptDumpFlags: u32 {
+        /// No flags set. Reserved by libcryptsetup for future use.
+        const EMPTY = 0;
+    }
+}
+
+bitflags! {
+    /// Flags describing the state of a currently active (mapped) device
+    pub struct CryptActivateFlags: u32 {
+        /// Device is read-only
+        const READONLY = cryptsetup_sys::CRYPT_ACTIVATE_READONLY;
+        /// Don't report device UUID
+        const NO_UUID = cryptsetup_sys::CRYPT_ACTIVATE_NO_UUID;
+        /// Shared device
+        const SHARED = cryptsetup_sys::CRYPT_ACTIVATE_SHARED;
+        /// Discards are allowed on this device
+        const ALLOW_DISCARDS = cryptsetup_sys::CRYPT_ACTIVATE_ALLOW_DISCARDS;
+        /// Unrestricted device activation
+        const PRIVATE = cryptsetup_sys::CRYPT_ACTIVATE_PRIVATE;
+        /// Corrupted device
+        const CORRUPTED = cryptsetup_sys::CRYPT_ACTIVATE_CORRUPTED;
+        /// Ignore integrity corruption, report it only
+        const IGNORE_CORRUPTION = cryptsetup_sys::CRYPT_ACTIVATE_IGNORE_CORRUPTION;
+        /// Restart device on integrity corruption
+        const RESTART_ON_CORRUPTION = cryptsetup_sys::CRYPT_ACTIVATE_RESTART_ON_CORRUPTION;
+        /// Recovery mode (no journal, no integrity checks)
+        const RECOVERY = cryptsetup_sys::CRYPT_ACTIVATE_RECOVERY;
+        /// Do not use journal for writes
+        const NO_JOURNAL = cryptsetup_sys::CRYPT_ACTIVATE_NO_JOURNAL;
+        /// Device is suspended
+        const SUSPENDED = cryptsetup_sys::CRYPT_ACTIVATE_SUSPENDED;
+    }
+}
+
+bitflags! {
+    /// Flags for Verity device parameters
+    pub struct CryptVerityFlags: u32 {
+        /// No on-disk header, all parameters must be provided by the caller
+        const NO_HEADER = cryptsetup_sys::CRYPT_VERITY_NO_HEADER;
+        /// Create hash - format hash device
+        const CREATE_HASH = cryptsetup_sys::CRYPT_VERITY_CREATE_HASH;
+        /// Verify data block hashes against the hash tree during activation
+        const CHECK_HASH = cryptsetup_sys::CRYPT_VERITY_CHECK_HASH;
+        /// Verify root hash signature in the in-kernel keyring
+        const ROOT_HASH_SIGNATURE = cryptsetup_sys::CRYPT_VERITY_ROOT_HASH_SIGNATURE;
+    }
+}
+
+/// Parameters describing a currently active (mapped) device, as reported by the kernel dm target
+pub struct ActiveDevice {
+    /// Offset in 512-byte sectors where real data starts
+    pub offset: u64,
+    /// IV offset in 512-byte sectors
+    pub iv_offset: u64,
+    /// Size of the active device in 512-byte sectors
+    pub size: u64,
+    /// Activation flags for this mapping
+    pub flags: CryptActivateFlags,
+}
+
+impl From<cryptsetup_sys::crypt_active_device> for ActiveDevice {
+    fn from(cad: cryptsetup_sys::crypt_active_device) -> Self {
+        ActiveDevice {
+            offset: cad.offset,
+            iv_offset: cad.iv_offset,
+            size: cad.size,
+            flags: CryptActivateFlags::from_bits_truncate(cad.flags),
+        }
+    }
+}
+
 pub enum CryptStatusInfo {
     Invalid = cryptsetup_sys::crypt_status_info_CRYPT_INVALID as isize,
     Inactive = cryptsetup_sys::crypt_status_info_CRYPT_INACTIVE as isize,
@@ -41,6 +115,25 @@ impl<'a> CryptDeviceStatus<'a> {
         CryptDeviceStatus { reference }
     }
 
+    /// Get runtime parameters of a currently active (mapped) device
+    pub fn get_active_device(&mut self, name: &str) -> Result<ActiveDevice, LibcryptErr> {
+        let name_cstring = to_cstring!(name)?;
+        let mut cad = cryptsetup_sys::crypt_active_device {
+            offset: 0,
+            iv_offset: 0,
+            size: 0,
+            flags: 0,
+        };
+        errno!(unsafe {
+            cryptsetup_sys::crypt_get_active_device(
+                self.reference.as_ptr(),
+                name_cstring.as_ptr(),
+                &mut cad as *mut _,
+            )
+        })
+        .map(|_| ActiveDevice::from(cad))
+    }
+
     /// Get status info from device name
     pub fn status(&mut self, name: &str) -> Result<CryptStatusInfo, LibcryptErr> {
         let name_cstring = to_cstring!(name)?;
@@ -55,6 +148,25 @@ impl<'a> CryptDeviceStatus<'a> {
         errno!(unsafe { cryptsetup_sys::crypt_dump(self.reference.as_ptr()) })
     }
 
+    /// Dump metadata about device as a JSON string, suitable for machine-readable consumers
+    pub fn dump_json(&mut self, flags: CryptDumpFlags) -> Result<String, LibcryptErr> {
+        let mut json_ptr: *const std::os::raw::c_char = std::ptr::null();
+        errno!(unsafe {
+            cryptsetup_sys::crypt_dump_json(
+                self.reference.as_ptr(),
+                &mut json_ptr as *mut _,
+                flags.bits(),
+            )
+        })
+        .and_then(|_| from_str_ptr_to_owned!(json_ptr))
+    }
+
+    /// Get device type (e.g. LUKS1, LUKS2, PLAIN, VERITY, INTEGRITY)
+    pub fn get_type(&mut self) -> Result<EncryptionFormat, LibcryptErr> {
+        from_str_ptr!(cryptsetup_sys::crypt_get_type(self.reference.as_ptr()))
+            .and_then(EncryptionFormat::from_str)
+    }
+
     /// Get cipher used by device
     pub fn get_cipher(&mut self) -> Result<String, LibcryptErr> {
         from_str_ptr_to_owned!(cryptsetup_sys::crypt_get_cipher(self.reference.as_ptr()))
@@ -81,6 +193,14 @@ impl<'a> CryptDeviceStatus<'a> {
         .map(Path::new)
     }
 
+    /// Check whether the LUKS header is detached from the data device
+    pub fn is_header_detached(&mut self) -> Result<bool, LibcryptErr> {
+        match unsafe { cryptsetup_sys::crypt_header_is_detached(self.reference.as_ptr()) } {
+            rc if rc < 0 => Err(LibcryptErr::IOError(std::io::Error::from_raw_os_error(-rc))),
+            rc => Ok(rc == 1),
+        }
+    }
+
     /// Get path to detached metadata device or `None` if it is attached
     pub fn get_metadata_device_path(&mut self) -> Result<Option<&Path>, LibcryptErr> {
         let ptr =
@@ -111,6 +231,20 @@ impl<'a> CryptDeviceStatus<'a> {
         unsafe { cryptsetup_sys::crypt_get_sector_size(self.reference.as_ptr()) }
     }
 
+    /// Get integrity algorithm used by device, or `None` if integrity is not configured
+    pub fn get_integrity(&mut self) -> Result<Option<String>, LibcryptErr> {
+        let ptr = unsafe { cryptsetup_sys::crypt_get_integrity(self.reference.as_ptr()) };
+        if ptr.is_null() {
+            return Ok(None);
+        }
+        from_str_ptr_to_owned!(ptr).map(Some)
+    }
+
+    /// Get size of the integrity tag in bytes
+    pub fn get_integrity_tag_size(&mut self) -> c_int {
+        unsafe { cryptsetup_sys::crypt_get_integrity_tag_size(self.reference.as_ptr()) }
+    }
+
     /// Get Verity device parameters
     pub fn get_verity_info(&mut self) -> Result<CryptParamsVerity, LibcryptErr> {
         let mut verity = cryptsetup_sys::crypt_params_verity {
@@ -135,6 +269,12 @@ impl<'a> CryptDeviceStatus<'a> {
         .and_then(|_| CryptParamsVerity::try_from(&verity))
     }
 
+    /// Get Verity device parameters' flags as a decoded `CryptVerityFlags` set
+    pub fn get_verity_flags(&mut self) -> Result<CryptVerityFlags, LibcryptErr> {
+        self.get_verity_info()
+            .map(|params| CryptVerityFlags::from_bits_truncate(params.flags))
+    }
+
     /// Get Integrity device parameters
     pub fn get_integrity_info(&mut self) -> Result<CryptParamsIntegrity, LibcryptErr> {
         let mut integrity = cryptsetup_sys::crypt_params_integrity {
@@ -0,0 +1,99 @@
+use std::{
+    os::raw::{c_int, c_void},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use crate::{device::CryptDevice, err::LibcryptErr};
+
+use bitflags::bitflags;
+use libcryptsetup_rs_sys as cryptsetup_sys;
+
+/// Pattern to write to the wiped region of a device
+pub enum CryptWipePattern {
+    Zero = cryptsetup_sys::crypt_wipe_pattern_CRYPT_WIPE_ZERO as isize,
+    Random = cryptsetup_sys::crypt_wipe_pattern_CRYPT_WIPE_RANDOM as isize,
+    EncryptedZero = cryptsetup_sys::crypt_wipe_pattern_CRYPT_WIPE_ENCRYPTED_ZERO as isize,
+    Special = cryptsetup_sys::crypt_wipe_pattern_CRYPT_WIPE_SPECIAL as isize,
+}
+
+bitflags! {
+    /// Flags for `crypt_wipe`
+    pub struct CryptWipeFlags: u32 {
+        /// Do not use direct I/O for wiping
+        const NO_DIRECT_IO = cryptsetup_sys::CRYPT_WIPE_NO_DIRECT_IO;
+    }
+}
+
+/// Progress callback invoked periodically during a wipe operation.
+///
+/// Takes the total `size` and current `offset` of the wipe, both in bytes.
+/// Return `true` to continue the operation or `false` to abort it.
+type WipeProgressCallback<'a> = Box<dyn FnMut(u64, u64) -> bool + 'a>;
+
+extern "C" fn wipe_progress_cb(size: u64, offset: u64, usrptr: *mut c_void) -> c_int {
+    let callback = usrptr as *mut WipeProgressCallback;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe { (*callback)(size, offset) }));
+    match result {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(_) => 1,
+    }
+}
+
+/// Handle for crypt device wipe operations
+pub struct CryptWipe<'a> {
+    reference: &'a mut CryptDevice,
+}
+
+impl<'a> CryptWipe<'a> {
+    pub(crate) fn new(reference: &'a mut CryptDevice) -> Self {
+        CryptWipe { reference }
+    }
+
+    /// Wipe `length` bytes of `dev_path` starting at `offset`, writing `pattern` in chunks of
+    /// `wipe_block_size` bytes.
+    ///
+    /// This is required to initialize checksums before activating an integrity-protected device.
+    /// If `progress` is provided, it is invoked periodically with the total size and current
+    /// offset of the wipe so that callers can report progress or abort early.
+    #[allow(clippy::too_many_arguments)]
+    pub fn wipe(
+        &mut self,
+        dev_path: &Path,
+        pattern: CryptWipePattern,
+        offset: u64,
+        length: u64,
+        wipe_block_size: usize,
+        flags: CryptWipeFlags,
+        progress: Option<Box<dyn FnMut(u64, u64) -> bool + 'a>>,
+    ) -> Result<(), LibcryptErr> {
+        let dev_path_str = dev_path.to_str().ok_or(LibcryptErr::InvalidConversion)?;
+        let dev_path_cstring = to_cstring!(dev_path_str)?;
+
+        let (callback, mut boxed_progress): (cryptsetup_sys::crypt_progress_cb, _) = match progress
+        {
+            Some(progress) => (Some(wipe_progress_cb as _), Some(progress)),
+            None => (None, None),
+        };
+        let usrptr = boxed_progress
+            .as_mut()
+            .map(|b| b as *mut WipeProgressCallback as *mut c_void)
+            .unwrap_or(std::ptr::null_mut());
+
+        errno!(unsafe {
+            cryptsetup_sys::crypt_wipe(
+                self.reference.as_ptr(),
+                dev_path_cstring.as_ptr(),
+                pattern as u32,
+                offset,
+                length,
+                wipe_block_size,
+                flags.bits(),
+                callback,
+                usrptr,
+            )
+        })
+        .map(|_| ())
+    }
+}